@@ -0,0 +1,36 @@
+mod mysql_backend;
+mod sqlite_backend;
+
+use std::sync::Arc;
+
+use calamine::DataType;
+
+use crate::error::Error;
+use crate::utils::Opts;
+use mysql_backend::MySQLBackend;
+use sqlite_backend::SQLiteBackend;
+
+/// A storage engine `excel2sql` can load a `Table` into, selected at
+/// runtime by `Opts::database_type`.
+pub trait Backend: Send + Sync {
+    fn table_exists(&self, table_name: &str) -> Result<bool, Error>;
+    fn create_table(&self, create_sql: &str) -> Result<(), Error>;
+    fn clear_table(&self, table_name: &str) -> Result<(), Error>;
+    fn insert_rows(
+        &self,
+        table_name: &str,
+        fields: &Vec<String>,
+        rows: &Vec<Vec<DataType>>,
+    ) -> Result<(), Error>;
+}
+
+pub fn build_backend(opts: &Opts) -> Result<Arc<dyn Backend>, Error> {
+    match opts.database_type.as_str() {
+        "mysql" => Ok(Arc::new(MySQLBackend::new(opts)?)),
+        "sqlite" => Ok(Arc::new(SQLiteBackend::new(opts)?)),
+        other => Err(Error::BackendError(format!(
+            "unsupported database type '{}'",
+            other
+        ))),
+    }
+}
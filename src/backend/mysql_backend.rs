@@ -0,0 +1,140 @@
+use calamine::DataType;
+use mysql::{Params, Pool, Value};
+
+use super::Backend;
+use crate::error::Error;
+use crate::utils::{make_batch_insert_sql, Opts};
+
+pub struct MySQLBackend {
+    pool: Pool,
+}
+
+impl MySQLBackend {
+    pub fn new(opts: &Opts) -> Result<Self, Error> {
+        let host = require_opt(&opts.host, "--host")?;
+        let user = require_opt(&opts.user, "--user")?;
+        let password = require_opt(&opts.password, "--password")?;
+        let port = opts.port.ok_or_else(|| {
+            Error::BackendError(String::from(
+                "--port is required for --database-type mysql",
+            ))
+        })?;
+
+        let mut builder = mysql::OptsBuilder::new();
+        builder
+            .db_name(Some(&opts.database))
+            .ip_or_hostname(Some(host))
+            .user(Some(user))
+            .tcp_port(port)
+            .pass(Some(password));
+
+        let pool = Pool::new(mysql::Opts::from(builder))?;
+        Ok(MySQLBackend { pool })
+    }
+}
+
+fn require_opt<'a>(value: &'a Option<String>, flag: &str) -> Result<&'a str, Error> {
+    value.as_deref().ok_or_else(|| {
+        Error::BackendError(format!("{} is required for --database-type mysql", flag))
+    })
+}
+
+impl Backend for MySQLBackend {
+    fn table_exists(&self, table_name: &str) -> Result<bool, Error> {
+        let result = self
+            .pool
+            .first_exec("SHOW TABLES LIKE ?", (table_name,))?;
+        Ok(result.is_some())
+    }
+
+    fn create_table(&self, create_sql: &str) -> Result<(), Error> {
+        self.pool.prep_exec(create_sql, ())?;
+        Ok(())
+    }
+
+    fn clear_table(&self, table_name: &str) -> Result<(), Error> {
+        self.pool
+            .prep_exec(format!("DELETE FROM `{}`", table_name), ())?;
+        Ok(())
+    }
+
+    fn insert_rows(
+        &self,
+        table_name: &str,
+        fields: &Vec<String>,
+        rows: &Vec<Vec<DataType>>,
+    ) -> Result<(), Error> {
+        let sql = make_batch_insert_sql(table_name, fields, rows.len());
+        let params = rows_to_params(rows);
+
+        let mut tx = self.pool.start_transaction(false, None, None)?;
+        match tx.prep_exec(sql, params) {
+            Ok(_) => {
+                tx.commit()?;
+                Ok(())
+            }
+            Err(e) => {
+                tx.rollback()?;
+                Err(Error::from(e))
+            }
+        }
+    }
+}
+
+// flattens every row in `rows` into a single positional params list, in the
+// row-major order expected by the multi-VALUES sql from `make_batch_insert_sql`
+fn rows_to_params(rows: &Vec<Vec<DataType>>) -> Params {
+    let values: Vec<Value> = rows.iter().flat_map(|row| row_to_values(row)).collect();
+    Params::Positional(values)
+}
+
+fn row_to_values(row: &Vec<DataType>) -> Vec<Value> {
+    row.iter()
+        .map(|cell| match cell {
+            DataType::String(v) => Value::Bytes(v.clone().into_bytes()),
+            DataType::Bool(v) => Value::Int(*v as i64),
+            DataType::Int(v) => Value::Int(*v),
+            DataType::Float(v) => Value::Float(*v),
+            _ => Value::NULL,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rows_to_params_v1() {
+        let params = rows_to_params(&vec![
+            vec![DataType::Int(1), DataType::String(String::from("Tom"))],
+            vec![DataType::Int(2), DataType::String(String::from("Amy"))],
+        ]);
+        assert_eq!(
+            params,
+            Params::Positional(vec![
+                Value::Int(1),
+                Value::Bytes(String::from("Tom").into_bytes()),
+                Value::Int(2),
+                Value::Bytes(String::from("Amy").into_bytes()),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_row_to_values_v1() {
+        let values = row_to_values(&vec![
+            DataType::Int(1),
+            DataType::String(String::from("Tom")),
+            DataType::Empty,
+        ]);
+        assert_eq!(
+            values,
+            vec![
+                Value::Int(1),
+                Value::Bytes(String::from("Tom").into_bytes()),
+                Value::NULL,
+            ]
+        )
+    }
+}
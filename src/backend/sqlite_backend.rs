@@ -0,0 +1,121 @@
+use std::sync::Mutex;
+
+use calamine::DataType;
+use rusqlite::{types::Null, Connection, ToSql};
+
+use super::Backend;
+use crate::error::Error;
+use crate::utils::{make_batch_insert_sql, Opts};
+
+pub struct SQLiteBackend {
+    conn: Mutex<Connection>,
+}
+
+impl SQLiteBackend {
+    pub fn new(opts: &Opts) -> Result<Self, Error> {
+        let conn = Connection::open(&opts.database)?;
+        Ok(SQLiteBackend {
+            conn: Mutex::new(conn),
+        })
+    }
+}
+
+impl Backend for SQLiteBackend {
+    fn table_exists(&self, table_name: &str) -> Result<bool, Error> {
+        let conn = self.conn.lock().unwrap();
+        let count: i64 = conn.query_row(
+            "SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name = ?",
+            [table_name],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    fn create_table(&self, create_sql: &str) -> Result<(), Error> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(create_sql, [])?;
+        Ok(())
+    }
+
+    fn clear_table(&self, table_name: &str) -> Result<(), Error> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(&format!("DELETE FROM `{}`", table_name), [])?;
+        Ok(())
+    }
+
+    fn insert_rows(
+        &self,
+        table_name: &str,
+        fields: &Vec<String>,
+        rows: &Vec<Vec<DataType>>,
+    ) -> Result<(), Error> {
+        let sql = make_batch_insert_sql(table_name, fields, rows.len());
+        let values: Vec<Box<dyn ToSql>> = rows.iter().flat_map(|row| row_to_values(row)).collect();
+        let params: Vec<&dyn ToSql> = values.iter().map(|v| v.as_ref()).collect();
+
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        match tx.execute(&sql, params.as_slice()) {
+            Ok(_) => {
+                tx.commit()?;
+                Ok(())
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+}
+
+fn row_to_values(row: &Vec<DataType>) -> Vec<Box<dyn ToSql>> {
+    row.iter()
+        .map(|cell| -> Box<dyn ToSql> {
+            match cell {
+                DataType::String(v) => Box::new(v.clone()),
+                DataType::Bool(v) => Box::new(*v as i64),
+                DataType::Int(v) => Box::new(*v),
+                DataType::Float(v) => Box::new(*v),
+                _ => Box::new(Null),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rusqlite::types::Value;
+
+    fn to_value(output: rusqlite::types::ToSqlOutput) -> Value {
+        match output {
+            rusqlite::types::ToSqlOutput::Owned(v) => v,
+            rusqlite::types::ToSqlOutput::Borrowed(v) => Value::from(v),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_row_to_values_v1() {
+        let values = row_to_values(&vec![
+            DataType::Int(1),
+            DataType::String(String::from("Tom")),
+            DataType::Bool(true),
+            DataType::Float(1.5),
+            DataType::Empty,
+        ]);
+
+        let converted: Vec<Value> = values
+            .iter()
+            .map(|v| to_value(v.to_sql().unwrap()))
+            .collect();
+
+        assert_eq!(
+            converted,
+            vec![
+                Value::Integer(1),
+                Value::Text(String::from("Tom")),
+                Value::Integer(1),
+                Value::Real(1.5),
+                Value::Null,
+            ]
+        )
+    }
+}
@@ -1,9 +1,13 @@
 pub use mysql::{Error as MySQLError};
 pub use calamine::{Error as CalaError};
+pub use rusqlite::{Error as SQLiteError};
 
 pub enum Error {
     MySQLError(MySQLError),
 	CalaError(CalaError),
+    SQLiteError(SQLiteError),
+    BackendError(String),
+    IoError(std::io::Error),
 }
 
 impl From<MySQLError> for Error {
@@ -18,11 +22,26 @@ impl From<CalaError> for Error {
     }
 }
 
+impl From<SQLiteError> for Error {
+    fn from(e: SQLiteError) -> Self {
+        Error::SQLiteError(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IoError(e)
+    }
+}
+
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Error::CalaError(e) => write!(f, "{}", e),
             Error::MySQLError(e) => write!(f, "{}", e),
+            Error::SQLiteError(e) => write!(f, "{}", e),
+            Error::BackendError(msg) => write!(f, "{}", msg),
+            Error::IoError(e) => write!(f, "{}", e),
         }
     }
 }
@@ -32,6 +51,9 @@ impl std::fmt::Debug for Error {
         match self {
             Error::CalaError(e) => write!(f, "{:?}", e),
             Error::MySQLError(e) => write!(f, "{:?}", e),
+            Error::SQLiteError(e) => write!(f, "{:?}", e),
+            Error::BackendError(msg) => write!(f, "{:?}", msg),
+            Error::IoError(e) => write!(f, "{:?}", e),
         }
     }
 }
@@ -41,6 +63,9 @@ impl std::error::Error for Error {
         match self {
             Error::CalaError(e) => Some(e),
             Error::MySQLError(e) => Some(e),
+            Error::SQLiteError(e) => Some(e),
+            Error::BackendError(_) => None,
+            Error::IoError(e) => Some(e),
         }
     }
 }
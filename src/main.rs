@@ -1,13 +1,11 @@
-#[macro_use] 
+#[macro_use]
 extern crate log;
 extern crate mysql;
 
+mod backend;
+mod error;
 mod utils;
 
-use utils::Opts;
-use structopt::StructOpt;
-
 fn main() {
-    let opts = Opts::from_args();
-    println!("{:?}", opts)
+    utils::parse();
 }
\ No newline at end of file
@@ -4,45 +4,101 @@ use std::path::Path;
 use std::sync::Arc;
 use std::thread;
 
-use crate::error::{CalaError, Error, MySQLError};
+use crate::backend::{self, Backend};
+use crate::error::{CalaError, Error};
 use calamine::{
     open_workbook, DataType, Range, RangeDeserializer, RangeDeserializerBuilder, Reader, Xlsx,
 };
-use mysql::Pool;
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Migrate data from excel to database")]
 pub struct Opts {
     #[structopt(short = "e", long = "excel")]
-    excel: String,
+    pub(crate) excel: String,
 
     #[structopt(short = "d", long = "database")]
-    database: String,
+    pub(crate) database: String,
 
     #[structopt(short = "t", long = "database-type", default_value = "mysql")]
-    database_type: String,
+    pub(crate) database_type: String,
 
     #[structopt(short = "h", long = "host")]
-    host: String,
+    pub(crate) host: Option<String>,
 
     #[structopt(short = "p", long = "port")]
-    port: u16,
+    pub(crate) port: Option<u16>,
 
     #[structopt(short = "U", long = "user")]
-    user: String,
+    pub(crate) user: Option<String>,
 
     #[structopt(short = "P", long = "password")]
-    password: String,
+    pub(crate) password: Option<String>,
 
     #[structopt(short = "c", long = "clear")]
-    clear: bool,
+    pub(crate) clear: bool,
 
     #[structopt(short = "s", long = "skip", default_value = "0")]
-    skip: usize,
+    pub(crate) skip: usize,
 
     #[structopt(short = "D", long = "django-style")]
-    django_style: bool,
+    pub(crate) django_style: bool,
+
+    #[structopt(short = "b", long = "batch-size", default_value = "500")]
+    pub(crate) batch_size: usize,
+
+    #[structopt(long = "create-tables")]
+    pub(crate) create_tables: bool,
+
+    #[structopt(long = "only-sheets")]
+    pub(crate) only_sheets: Option<String>,
+
+    #[structopt(long = "except-sheets")]
+    pub(crate) except_sheets: Option<String>,
+
+    #[structopt(short = "j", long = "jobs", default_value = "4")]
+    pub(crate) jobs: usize,
+}
+
+// which sheets `parse_excel` should import, mirroring diesel's
+// `print_schema` `Filtering` (`OnlyTables` / `ExceptTables`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filtering {
+    None,
+    OnlyTables(Vec<String>),
+    ExceptTables(Vec<String>),
+}
+
+impl Filtering {
+    pub fn from_opts(opts: &Opts) -> Self {
+        if let Some(only) = &opts.only_sheets {
+            Filtering::OnlyTables(only.split(',').map(|s| s.trim().to_string()).collect())
+        } else if let Some(except) = &opts.except_sheets {
+            Filtering::ExceptTables(except.split(',').map(|s| s.trim().to_string()).collect())
+        } else {
+            Filtering::None
+        }
+    }
+
+    pub fn should_ignore(&self, sheet_name: &str) -> bool {
+        match self {
+            Filtering::None => false,
+            Filtering::OnlyTables(names) => !names.iter().any(|n| n == sheet_name),
+            Filtering::ExceptTables(names) => names.iter().any(|n| n == sheet_name),
+        }
+    }
+}
+
+// widest VARCHAR a column is allowed before it is widened to TEXT instead
+const VARCHAR_MAX_LEN: usize = 255;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum InferredType {
+    Empty,
+    Bool,
+    Int,
+    Float,
+    String,
 }
 
 #[derive(Debug)]
@@ -99,11 +155,15 @@ impl Table {
     }
 }
 
-pub fn parse_excel(filepath: &str) -> Result<Vec<Table>, CalaError> {
+pub fn parse_excel(filepath: &str, filtering: &Filtering) -> Result<Vec<Table>, CalaError> {
     let mut tables = Vec::new();
     let mut workbook: Xlsx<_> = open_workbook(&filepath)?;
 
     for sheet_name in workbook.sheet_names().to_owned().iter() {
+        if filtering.should_ignore(sheet_name) {
+            continue;
+        }
+
         if let Some(Ok(range)) = workbook.worksheet_range(sheet_name) {
             tables.push(Table::new(sheet_name, range)?);
         } else {
@@ -114,9 +174,122 @@ pub fn parse_excel(filepath: &str) -> Result<Vec<Table>, CalaError> {
     Ok(tables)
 }
 
+// reads a delimited file as a single queryable `Table`, mirroring rusqlite's
+// `csvtab`: the header row becomes `fields`, every other row is sniffed cell
+// by cell into a `DataType`, and the table name is derived from the file stem
+pub fn parse_csv(filepath: &str) -> Result<Table, Error> {
+    let content = std::fs::read_to_string(filepath)?;
+    let mut records = parse_csv_records(&content).into_iter();
+
+    let fields = records.next().unwrap_or_default();
+    let width = fields.len().max(1);
+
+    let mut cells: Vec<calamine::Cell<DataType>> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| calamine::Cell::new((0, i as u32), DataType::String(f.clone())))
+        .collect();
+
+    let mut row_count = 1usize;
+    for record in records {
+        for i in 0..width {
+            let raw = record.get(i).map(String::as_str).unwrap_or("");
+            cells.push(calamine::Cell::new(
+                (row_count as u32, i as u32),
+                sniff_csv_cell(raw),
+            ));
+        }
+        row_count += 1;
+    }
+
+    let range = Range::from_sparse(cells);
+    let table_name = Path::new(filepath)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("table");
+
+    Ok(Table::new(table_name, range)?)
+}
+
+// splits RFC4180-style CSV content into records of fields: commas/newlines
+// inside a `"..."`-quoted field don't end the field/record, and `""` inside
+// a quoted field is an escaped literal quote. Unquoted fields are trimmed;
+// quoted ones are kept verbatim. Blank lines are dropped.
+fn parse_csv_records(content: &str) -> Vec<Vec<String>> {
+    let mut records: Vec<Vec<String>> = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut quoted = false;
+    let mut saw_any_char = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        saw_any_char = true;
+
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() && !quoted => {
+                in_quotes = true;
+                quoted = true;
+            }
+            ',' => {
+                record.push(if quoted { field.clone() } else { field.trim().to_string() });
+                field.clear();
+                quoted = false;
+            }
+            '\r' => {}
+            '\n' => {
+                record.push(if quoted { field.clone() } else { field.trim().to_string() });
+                field.clear();
+                quoted = false;
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if saw_any_char && (!field.is_empty() || !record.is_empty() || quoted) {
+        record.push(if quoted { field } else { field.trim().to_string() });
+        records.push(record);
+    }
+
+    records
+        .into_iter()
+        .filter(|r| !(r.len() == 1 && r[0].is_empty()))
+        .collect()
+}
+
+fn sniff_csv_cell(raw: &str) -> DataType {
+    if raw.is_empty() {
+        DataType::Empty
+    } else if let Ok(v) = raw.parse::<i64>() {
+        DataType::Int(v)
+    } else if let Ok(v) = raw.parse::<f64>() {
+        DataType::Float(v)
+    } else if let Ok(v) = raw.parse::<bool>() {
+        DataType::Bool(v)
+    } else {
+        DataType::String(String::from(raw))
+    }
+}
+
 pub fn import_table_to_database(
     opts: Arc<Opts>,
-    pool: Arc<Pool>,
+    backend: Arc<dyn Backend>,
     table: Table,
 ) -> Result<(u32, String), Error> {
     let table_name = if opts.django_style {
@@ -125,32 +298,52 @@ pub fn import_table_to_database(
         table.name.clone()
     };
 
-    check_table_exists(&table_name, pool.as_ref())?;
+    if opts.create_tables {
+        if !backend.table_exists(&table_name)? {
+            let create_sql = infer_create_table_sql(&table_name, &table, opts.skip)?;
+            backend.create_table(&create_sql)?;
+        }
+    } else if !backend.table_exists(&table_name)? {
+        return Err(Error::BackendError(format!(
+            "Table '{}' doesn't exist",
+            table_name
+        )));
+    }
 
     if opts.clear {
-        pool.prep_exec(format!("DELETE FROM {}", table_name), ())?;
+        backend.clear_table(&table_name)?;
     }
 
     let mut count = 0;
+    let mut batch: Vec<Vec<DataType>> = Vec::with_capacity(opts.batch_size);
+
     for row in table.iter_rows(opts.skip)? {
         if let Ok(r) = row {
             count += 1;
-            let sql = make_insert_sql(&table_name, &table.fields, &r);
-            pool.prep_exec(sql, ())?;
+            batch.push(r);
+
+            if batch.len() == opts.batch_size {
+                backend.insert_rows(&table_name, &table.fields, &batch)?;
+                batch.clear();
+            }
         } else {
             warn!("Invalid row of {}: {:?}", table_name, row);
         }
     }
 
+    if !batch.is_empty() {
+        backend.insert_rows(&table_name, &table.fields, &batch)?;
+    }
+
     Ok((count, table_name))
 }
 
-// insert into table_name (`c1`, `c2`, `c3`, `c4`) values (`:1`, `:2`, `:3`, `:4`);
-pub fn make_insert_sql(table_name: &str, fields: &Vec<String>, row: &Vec<DataType>) -> String {
+// insert into table_name (`c1`, `c2`) values (?, ?), (?, ?), ..., (?, ?); -- `row_count` groups
+pub fn make_batch_insert_sql(table_name: &str, fields: &Vec<String>, row_count: usize) -> String {
     let mut result: String = format!("INSERT INTO `{}` (", table_name);
 
     let fields_len = fields.len();
-    for i in 0..fields.len() {
+    for i in 0..fields_len {
         result.push_str("`");
         result.push_str(&fields[i]);
         result.push_str("`");
@@ -160,39 +353,95 @@ pub fn make_insert_sql(table_name: &str, fields: &Vec<String>, row: &Vec<DataTyp
         }
     }
 
-    result.push_str(") VALUES (");
+    result.push_str(") VALUES ");
 
-    let row_len = row.len();
-    for i in 0..row_len {
-        match &row[i] {
-            DataType::String(v) => result.push_str(format!("\"{}\"", v).as_str()),
-            DataType::Bool(v) => result.push_str(format!("{}", *v as i32).as_str()),
-            DataType::Int(v) => result.push_str(format!("{}", v).as_str()),
-            DataType::Float(v) => result.push_str(format!("{}", v).as_str()),
-            _ => result.push_str("null"),
+    let mut placeholders = String::from("(");
+    for i in 0..fields_len {
+        placeholders.push_str("?");
+        if i != fields_len - 1 {
+            placeholders.push_str(", ");
         }
+    }
+    placeholders.push_str(")");
 
-        if i != row_len - 1 {
-            result.push_str(", ");
+    let groups: Vec<&str> = (0..row_count).map(|_| placeholders.as_str()).collect();
+    result.push_str(groups.join(", ").as_str());
+    result.push_str(";");
+
+    result
+}
+
+// scans the sheet's data rows to infer a SQL type per column, then builds a
+// `CREATE TABLE IF NOT EXISTS` from `table.fields` plus the inferred types
+pub fn infer_create_table_sql(
+    table_name: &str,
+    table: &Table,
+    skip: usize,
+) -> Result<String, CalaError> {
+    let mut widest = vec![InferredType::Empty; table.fields.len()];
+    let mut max_lens = vec![0usize; table.fields.len()];
+
+    for row in table.iter_rows(skip)? {
+        if let Ok(r) = row {
+            for (i, cell) in r.iter().enumerate() {
+                if i >= widest.len() {
+                    break;
+                }
+
+                let (rank, len) = classify_cell(cell);
+                if rank > widest[i] {
+                    widest[i] = rank;
+                }
+                if len > max_lens[i] {
+                    max_lens[i] = len;
+                }
+            }
         }
     }
 
-    result.push_str(");");
+    let columns: Vec<String> = table
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            if field == "id" {
+                format!("`{}` BIGINT PRIMARY KEY", field)
+            } else {
+                format!("`{}` {}", field, sql_type_for(widest[i], max_lens[i]))
+            }
+        })
+        .collect();
 
-    result
+    Ok(format!(
+        "CREATE TABLE IF NOT EXISTS `{}` ({});",
+        table_name,
+        columns.join(", ")
+    ))
 }
 
-pub fn check_table_exists(table_name: &str, pool: &Pool) -> Result<(), MySQLError> {
-    let result = pool.first_exec(format!("SHOW TABLES LIKE \"{}\"", table_name), ())?;
+fn classify_cell(cell: &DataType) -> (InferredType, usize) {
+    match cell {
+        DataType::String(v) => (InferredType::String, v.chars().count()),
+        DataType::Bool(_) => (InferredType::Bool, 0),
+        DataType::Int(_) => (InferredType::Int, 0),
+        DataType::Float(_) => (InferredType::Float, 0),
+        _ => (InferredType::Empty, 0),
+    }
+}
 
-    if result.is_none() {
-        Err(MySQLError::from(mysql::MySqlError {
-            state: String::from("-1"),
-            message: format!("Table '{}' doesn't exist", table_name),
-            code: 99,
-        }))
-    } else {
-        Ok(())
+fn sql_type_for(widest: InferredType, max_len: usize) -> String {
+    match widest {
+        InferredType::Empty => String::from("TEXT NULL"),
+        InferredType::Bool => String::from("TINYINT(1)"),
+        InferredType::Int => String::from("BIGINT"),
+        InferredType::Float => String::from("DOUBLE"),
+        InferredType::String => {
+            if max_len > VARCHAR_MAX_LEN {
+                String::from("TEXT")
+            } else {
+                format!("VARCHAR({})", max_len.max(1))
+            }
+        }
     }
 }
 
@@ -213,35 +462,62 @@ pub fn make_django_style_table_name(filepath: &str, table_name: &str) -> String
 pub fn parse() {
     let opts = Arc::new(Opts::from_args());
 
-    let mut builder = mysql::OptsBuilder::new();
-    builder
-        .db_name(Some(&opts.database))
-        .ip_or_hostname(Some(&opts.host))
-        .user(Some(&opts.user))
-        .tcp_port(opts.port)
-        .pass(Some(&opts.password));
+    let backend = match backend::build_backend(&opts) {
+        Ok(b) => b,
+        Err(e) => {
+            println!("ERROR: {}", e);
+            return;
+        }
+    };
 
-    let pool = Arc::new(Pool::new(mysql::Opts::from(builder)).unwrap());
-    let parse_result = parse_excel(&opts.excel);
+    let filtering = Filtering::from_opts(&opts);
+    let parse_result: Result<Vec<Table>, Error> = if opts.excel.ends_with(".csv") {
+        parse_csv(&opts.excel).map(|table| vec![table])
+    } else {
+        parse_excel(&opts.excel, &filtering).map_err(Error::from)
+    };
 
     if let Ok(result) = parse_result {
-        for mut table in result.into_iter() {
+        let mut tables: Vec<Table> = result;
+        for table in tables.iter_mut() {
             if opts.django_style {
                 table.to_django_style_fields();
             }
+        }
 
-            let cloned_opts = opts.clone();
-            let cloned_pool = pool.clone();
-
-            let th = thread::spawn(move || {
-                let result = import_table_to_database(cloned_opts, cloned_pool, table);
-                match result {
-                    Ok(r) => println!("Import {} rows for {}", r.0, r.1),
-                    Err(e) => println!("ERROR:> {}", e),
-                }
-            });
+        let jobs = opts.jobs.max(1);
+        let mut reports: Vec<Result<(u32, String), Error>> = Vec::with_capacity(tables.len());
+
+        // spawn up to `jobs` worker threads at a time, join the batch, then
+        // move on to the next one; a failing or panicking sheet is recorded
+        // in `reports` instead of unwinding the program. All workers share
+        // the one backend: the MySQL backend takes a pool connection per
+        // call, the SQLite backend serializes them on a single connection
+        while !tables.is_empty() {
+            let batch_size = tables.len().min(jobs);
+            let handles: Vec<_> = tables
+                .drain(0..batch_size)
+                .map(|table| {
+                    let cloned_opts = opts.clone();
+                    let cloned_backend = backend.clone();
+
+                    thread::spawn(move || import_table_to_database(cloned_opts, cloned_backend, table))
+                })
+                .collect();
+
+            for handle in handles {
+                let report = handle.join().unwrap_or_else(|e| {
+                    Err(Error::BackendError(format!("worker panicked: {:?}", e)))
+                });
+                reports.push(report);
+            }
+        }
 
-            th.join().unwrap();
+        for report in &reports {
+            match report {
+                Ok(r) => println!("Import {} rows for {}", r.0, r.1),
+                Err(e) => println!("ERROR:> {}", e),
+            }
         }
     } else {
         println!("ERROR: {}", parse_result.unwrap_err());
@@ -254,7 +530,7 @@ mod test {
 
     #[test]
     fn test_parse_excel() {
-        let result = parse_excel("manifest/main.xlsx");
+        let result = parse_excel("manifest/main.xlsx", &Filtering::None);
         if let Ok(r) = result {
             assert_eq!(r.len(), 8);
 
@@ -268,6 +544,92 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_sniff_csv_cell_v1() {
+        assert_eq!(sniff_csv_cell(""), DataType::Empty);
+        assert_eq!(sniff_csv_cell("42"), DataType::Int(42));
+        assert_eq!(sniff_csv_cell("3.14"), DataType::Float(3.14));
+        assert_eq!(sniff_csv_cell("true"), DataType::Bool(true));
+        assert_eq!(
+            sniff_csv_cell("Tom"),
+            DataType::String(String::from("Tom"))
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_records_v1() {
+        let records = parse_csv_records("id,name,score\n1,Tom,9.5\n2,Amy,\n");
+        assert_eq!(
+            records,
+            vec![
+                vec!["id", "name", "score"],
+                vec!["1", "Tom", "9.5"],
+                vec!["2", "Amy", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_records_quoted_v1() {
+        // an embedded comma, an embedded newline, and an escaped `""` quote,
+        // all inside quoted fields, must not split the record early
+        let records = parse_csv_records(
+            "id,name,note\n1,\"Smith, John\",\"line one\nline two\"\n2,Amy,\"she said \"\"hi\"\"\"\n",
+        );
+        assert_eq!(
+            records,
+            vec![
+                vec!["id", "name", "note"],
+                vec!["1", "Smith, John", "line one\nline two"],
+                vec!["2", "Amy", "she said \"hi\""],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_v1() {
+        let path = std::env::temp_dir().join("excel2sql_test_parse_csv_v1.csv");
+        std::fs::write(
+            &path,
+            "id,name,score\n1,\"Smith, Tom\",9.5\n2,Amy,\n",
+        )
+        .unwrap();
+
+        let table = parse_csv(path.to_str().unwrap()).unwrap();
+
+        assert_eq!(
+            table.fields,
+            vec![
+                String::from("id"),
+                String::from("name"),
+                String::from("score")
+            ]
+        );
+
+        let rows: Vec<Vec<DataType>> = table
+            .iter_rows(0)
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    DataType::Int(1),
+                    DataType::String(String::from("Smith, Tom")),
+                    DataType::Float(9.5)
+                ],
+                vec![
+                    DataType::Int(2),
+                    DataType::String(String::from("Amy")),
+                    DataType::Empty
+                ],
+            ]
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn test_make_django_style_table_name_v1() {
         let filepath = "/root/developenv/rustlang/excel2sql/manifest/main.xlsx";
@@ -293,38 +655,99 @@ mod test {
     }
 
     #[test]
-    fn test_check_table_exists_v1() {
-        let mut builder = mysql::OptsBuilder::new();
-        builder
-            .db_name(Some("UBOX_english_hn_lt"))
-            .ip_or_hostname(Some("localhost"))
-            .user(Some("root"))
-            .pass(Some("123456"));
+    fn test_infer_create_table_sql_v1() {
+        let table = Table {
+            name: String::from("people"),
+            fields: vec![String::from("id"), String::from("name")],
+            range: Range::empty(),
+        };
+
+        let sql = infer_create_table_sql("people", &table, 0).unwrap();
+        assert_eq!(
+            sql,
+            "CREATE TABLE IF NOT EXISTS `people` (`id` BIGINT PRIMARY KEY, `name` TEXT NULL);"
+        )
+    }
 
-        let pool = Pool::new(mysql::Opts::from(builder)).unwrap();
+    #[test]
+    fn test_sql_type_for_v1() {
+        assert_eq!(sql_type_for(InferredType::Int, 0), "BIGINT");
+        assert_eq!(sql_type_for(InferredType::Float, 0), "DOUBLE");
+        assert_eq!(sql_type_for(InferredType::Bool, 0), "TINYINT(1)");
+        assert_eq!(sql_type_for(InferredType::Empty, 0), "TEXT NULL");
+        assert_eq!(sql_type_for(InferredType::String, 10), "VARCHAR(10)");
+        assert_eq!(sql_type_for(InferredType::String, 1000), "TEXT");
+    }
 
-        assert!(check_table_exists("main_video", &pool).is_ok());
-        assert!(check_table_exists("haha", &pool).is_err());
+    #[test]
+    fn test_filtering_should_ignore_v1() {
+        let only = Filtering::OnlyTables(vec![String::from("Video"), String::from("User")]);
+        assert!(!only.should_ignore("Video"));
+        assert!(only.should_ignore("Notes"));
+
+        let except = Filtering::ExceptTables(vec![String::from("Notes")]);
+        assert!(except.should_ignore("Notes"));
+        assert!(!except.should_ignore("Video"));
+
+        assert!(!Filtering::None.should_ignore("anything"));
     }
 
     #[test]
-    fn test_make_insert_sql_v1() {
-        let sql = make_insert_sql(
+    fn test_filtering_from_opts_trims_whitespace_v1() {
+        let mut opts = test_opts();
+        opts.only_sheets = Some(String::from("Sheet1, Sheet2 , Sheet3"));
+
+        let filtering = Filtering::from_opts(&opts);
+        assert_eq!(
+            filtering,
+            Filtering::OnlyTables(vec![
+                String::from("Sheet1"),
+                String::from("Sheet2"),
+                String::from("Sheet3"),
+            ])
+        );
+        assert!(!filtering.should_ignore("Sheet2"));
+
+        opts.only_sheets = None;
+        opts.except_sheets = Some(String::from(" Notes , Scratch"));
+
+        let filtering = Filtering::from_opts(&opts);
+        assert_eq!(
+            filtering,
+            Filtering::ExceptTables(vec![String::from("Notes"), String::from("Scratch")])
+        );
+    }
+
+    fn test_opts() -> Opts {
+        Opts {
+            excel: String::from("manifest/main.xlsx"),
+            database: String::from("test"),
+            database_type: String::from("mysql"),
+            host: None,
+            port: None,
+            user: None,
+            password: None,
+            clear: false,
+            skip: 0,
+            django_style: false,
+            batch_size: 500,
+            create_tables: false,
+            only_sheets: None,
+            except_sheets: None,
+            jobs: 4,
+        }
+    }
+
+    #[test]
+    fn test_make_batch_insert_sql_v1() {
+        let sql = make_batch_insert_sql(
             "UBOX_english_hn_lt",
-            &vec![
-                String::from("id"),
-                String::from("name"),
-                String::from("age"),
-            ],
-            &vec![
-                DataType::Int(1),
-                DataType::String(String::from("Tom")),
-                DataType::Int(12),
-            ],
+            &vec![String::from("id"), String::from("name")],
+            2,
         );
         assert_eq!(
             sql,
-            "INSERT INTO `UBOX_english_hn_lt` (`id`, `name`, `age`) VALUES (1, \"Tom\", 12);"
+            "INSERT INTO `UBOX_english_hn_lt` (`id`, `name`) VALUES (?, ?), (?, ?);"
         )
     }
 }